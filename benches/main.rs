@@ -26,9 +26,9 @@ fn criterion_benchmark(c: &mut Criterion) {
         ("thanks".into(), DummyVal(1005)),
     ];
     
-    let m = SimdPerfectScanMap::<DummyVal, 8, N_LANES>::try_from(kvs.clone()).unwrap(); // we clone here so we can reuse the kvs for other benchmarks
-    assert_eq!(m.get(&"key1".into()), Some(&DummyVal(1001)));
-    assert_eq!(m.get(&"another".into()), Some(&DummyVal(1004)));
+    let m = SimdPerfectScanMap::<String, DummyVal, 8, N_LANES>::try_from(kvs.clone()).unwrap(); // we clone here so we can reuse the kvs for other benchmarks
+    assert_eq!(m.get("key1"), Some(&DummyVal(1001)));
+    assert_eq!(m.get("another"), Some(&DummyVal(1004)));
 
     let mut values: Vec<String> = Vec::with_capacity(10000);
     for _ in 0..values.capacity() {
@@ -63,9 +63,9 @@ fn criterion_benchmark(c: &mut Criterion) {
         ("something_b".into(), DummyVal(1006))
     ];
     
-    let m = SimdPerfectScanMap::<DummyVal, 8, N_LANES>::try_from(kvs.clone()).unwrap(); // we clone here so we can reuse the kvs for other benchmarks
-    assert_eq!(m.get(&"key1".into()), Some(&DummyVal(1001)));
-    assert_eq!(m.get(&"key1longer".into()), Some(&DummyVal(1002)));
+    let m = SimdPerfectScanMap::<String, DummyVal, 8, N_LANES>::try_from(kvs.clone()).unwrap(); // we clone here so we can reuse the kvs for other benchmarks
+    assert_eq!(m.get("key1"), Some(&DummyVal(1001)));
+    assert_eq!(m.get("key1longer"), Some(&DummyVal(1002)));
 
     let mut values: Vec<String> = Vec::with_capacity(10000);
     for _ in 0..values.capacity() {
@@ -101,7 +101,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         }
         let mut value_iter = values.iter().cycle(); 
 
-        let m = SimdPerfectScanMap::<DummyVal, 32, N_LANES>::try_from(kvs.clone()).unwrap(); 
+        let m = SimdPerfectScanMap::<String, DummyVal, 32, N_LANES>::try_from(kvs.clone()).unwrap(); 
         assert_eq!(m.get(&kvs[0].0), Some(&kvs[0].1));
         assert_eq!(m.get(&kvs[2].0), Some(&kvs[2].1));
         group.bench_with_input(BenchmarkId::new("SimdPerfectScanMap", size), &size, |b, _| {
@@ -13,19 +13,22 @@ fn roughly_log_2(x: usize) -> usize {
 }
 
 /// Use `::try_from()` to construct an instance. It is immutable after construction; query it with `.get()`, or `.iter()`.
-/// 
+///
+/// `K` is the owned key type, e.g. `String` or `Vec<u8>`; it only needs `AsRef<[u8]>` since keys are compared byte-for-byte,
+/// so arbitrary binary keys (including ones containing interior NUL bytes) are fine, not just NUL-free UTF-8.
+///
 /// Set `LANE_SIZE` to the maxium available SIMD width for the target architecture, in bytes; ideally 64, but 16 is still not too bad.
 /// Note portable_simd will work with any value via emulation, but it's not a good idea to do extra work if it's expensive.
-/// 
+///
 /// To pick a value for `MAX_LANES`, you really need to benchmark against alternative map implementations to see how many lanes of
 /// work can be executed here before it becomes slower than another map implementation.
-/// 
-/// It is best suited to <100 keys, but you can stretch things further with a large enough value for `MAX_LANES`. 
+///
+/// It is best suited to <100 keys, but you can stretch things further with a large enough value for `MAX_LANES`.
 #[derive(Debug)]
-pub struct SimdPerfectScanMap<T, const MAX_LANES: usize, const LANE_SIZE: usize> 
-where LaneCount<LANE_SIZE>: SupportedLaneCount
+pub struct SimdPerfectScanMap<K, T, const MAX_LANES: usize, const LANE_SIZE: usize>
+where K: AsRef<[u8]>, LaneCount<LANE_SIZE>: SupportedLaneCount
 {
-    key_vals: Vec<(String, T)>,
+    key_vals: Vec<(K, T)>,
     n_lanes_of_entities: usize,
     n_chars: usize,
     // we allocate the below as inline arrays, but we only need n_lanes_of_entities * n_chars elements (always <= MAX_LANES)
@@ -35,15 +38,15 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
 }
 
 
-impl<T, const MAX_LANES: usize,  const LANE_SIZE: usize> TryFrom<Vec<(String, T)>>
-for SimdPerfectScanMap<T, MAX_LANES, LANE_SIZE>  
-where LaneCount<LANE_SIZE>: SupportedLaneCount
+impl<K, T, const MAX_LANES: usize,  const LANE_SIZE: usize> TryFrom<Vec<(K, T)>>
+for SimdPerfectScanMap<K, T, MAX_LANES, LANE_SIZE>
+where K: AsRef<[u8]>, LaneCount<LANE_SIZE>: SupportedLaneCount
 {
-    type Error = (&'static str, Vec<(String, T)>);
+    type Error = (&'static str, Vec<(K, T)>);
 
     /// If there's an error, it returns back ownership of the `key_vals` in the second element of the Err tuple in case you want
     /// to support using an alternative fallback map of some other kind.
-    fn try_from(key_vals: Vec<(String, T)>) -> Result<Self, Self::Error> {
+    fn try_from(key_vals: Vec<(K, T)>) -> Result<Self, Self::Error> {
         if key_vals.len() == 0 {
             return Err(("Empty map not supported", key_vals));
         }
@@ -52,16 +55,18 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
             return Err(("Too many keys to perform even a single scan", key_vals));
         }
 
-        let max_len = key_vals.iter().map(|(k, _)| k.as_bytes().len()).max().unwrap().min(MAX_KEY_SEARCH_LEN);
+        // +1 so the position just past the longest key is a candidate too (while it is inside MAX_KEY_SEARCH_LEN): there the
+        // longest key pads to 0 while every shorter key pads to a non-zero value, which separates e.g. `b"ab"` from `b"ab\0"`.
+        let max_len = (key_vals.iter().map(|(k, _)| k.as_ref().len()).max().unwrap() + 1).min(MAX_KEY_SEARCH_LEN);
 
         let n_lanes_of_entities = key_vals.len().div_ceil(LANE_SIZE);
         let mut solved = false;
         let mut positions = vec![0; 0];
-        
+
         // Yes, there are a lot of nested loops here, but MAX_LANES and max_key_len are capped fairly low.
         // If needed there are definitely some straightforward ways to reduce the complexity here, such as by storing the
         // selected characters themselves (as we end up doing in the `indexes` later) and sort after each new char so that
-        // duplicates appear next to one another. Then when adding a new char you just need to loop over existing block of 
+        // duplicates appear next to one another. Then when adding a new char you just need to loop over existing block of
         // duplicates rather than all other keys, and count how many are still dups as you go. But in reality this is taking
         // less than 1ms at startup so it's not worth over complicating.
         for _ in 1..=(MAX_LANES/n_lanes_of_entities) {
@@ -72,22 +77,22 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
                     continue;
                 }
                 positions.push(new_char_idx); // temporarily add it to calculate a score
-            
+
                 for (k_self, _) in &key_vals {
                     // each key contributes to the score for new_char_idx...
-                    let k_self = k_self.as_bytes();
+                    let k_self = k_self.as_ref();
                     let mut tests_matches_keys = vec![true; key_vals.len()];
                     for &char_idx_sub in positions[..].iter() {
                         // we could pad with zero beyond the end of a key, but instead we pad with 0, 1, 2, 3, ... as that's more valuable when scanning
                         let char_self =  *k_self.get(char_idx_sub).unwrap_or(&((char_idx_sub.wrapping_sub(k_self.len()) as u8)));
                         for (idx, (k_other, _)) in key_vals.iter().enumerate() {
-                            let k_other = k_other.as_bytes();
+                            let k_other = k_other.as_ref();
                             let char_other = *k_other.get(char_idx_sub).unwrap_or(&((char_idx_sub.wrapping_sub(k_other.len())) as u8)) ;
                             tests_matches_keys[idx] &= char_self == char_other;
                         }
                     }
                     let tests_scan_n_other_keys: usize = tests_matches_keys.iter().map(|&b| b as usize).sum::<usize>() - 1;
-                    position_score[new_char_idx] += roughly_log_2(tests_scan_n_other_keys); 
+                    position_score[new_char_idx] += roughly_log_2(tests_scan_n_other_keys);
                 }
 
                 positions.pop(); // as promised, adding the new_char was only temporary
@@ -104,7 +109,7 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
             return Err(("Unable to 'solve' with a sufficiently small number of scans", key_vals));
         }
 
-        let n_chars = positions.len();   
+        let n_chars = positions.len();
 
         let mut indexes = [Simd::<u8, LANE_SIZE>::splat(0); MAX_LANES];
         let mut n_valid = [0; MAX_LANES];
@@ -118,7 +123,7 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
                 let start_idx = lane_idx * LANE_SIZE;
                 let end_idx = if start_idx + LANE_SIZE > key_vals.len() { key_vals.len() } else { start_idx + LANE_SIZE };
                 for (idx, (k, _)) in key_vals[start_idx..end_idx].iter().enumerate() {
-                    let k = k.as_bytes();
+                    let k = k.as_ref();
                     v[idx] = *k.get(char_positions[scan_idx]).unwrap_or(&((char_positions[scan_idx].wrapping_sub(k.len())) as u8));
                 }
                 indexes[test_idx] = v;
@@ -126,7 +131,7 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
             }
         }
 
-        return Ok(SimdPerfectScanMap::<T, MAX_LANES, LANE_SIZE>{
+        return Ok(SimdPerfectScanMap::<K, T, MAX_LANES, LANE_SIZE>{
             n_lanes_of_entities,
             n_chars,
             char_positions,
@@ -138,14 +143,17 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
 }
 
 
-impl<T, const MAX_LANES: usize,  const LANE_SIZE: usize>
-SimdPerfectScanMap<T, MAX_LANES, LANE_SIZE>
-where LaneCount<LANE_SIZE>: SupportedLaneCount
+impl<K, T, const MAX_LANES: usize,  const LANE_SIZE: usize>
+SimdPerfectScanMap<K, T, MAX_LANES, LANE_SIZE>
+where K: AsRef<[u8]>, LaneCount<LANE_SIZE>: SupportedLaneCount
 {
-    /// This is branchless when compiled, except for the loops and the final validaiton check. The loops always make the same number 
+    /// This is branchless when compiled, except for the loops and the final validaiton check. The loops always make the same number
     /// of iterations for a given instance, with no early-exit conditions. This should keep the branch predictor happy.
-    pub fn get(&self, query: &String) -> Option<&T>{
-        let query = query.as_bytes();
+    ///
+    /// Accepts any query type that's cheaply viewable as bytes (`&str`, `&[u8]`, `&String`, ...), so looking a key up never
+    /// requires allocating a `String` just to match the stored key type `K`.
+    pub fn get<Q: AsRef<[u8]> + ?Sized>(&self, query: &Q) -> Option<&T>{
+        let query = query.as_ref();
         unsafe {
             // SAFETY: designed that way in `try_from` method, which is the only way to construct this struct
             hint::assert_unchecked(self.n_lanes_of_entities >= 1);
@@ -155,21 +163,23 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
         let mut matched_idx = 0;
         let mut test_idx = 0;
         for lane_idx in 0..self.n_lanes_of_entities {
-            let matched: [i8; LANE_SIZE] = array::from_fn(|i| (LANE_SIZE - i) as i8); 
+            let matched: [i8; LANE_SIZE] = array::from_fn(|i| (LANE_SIZE - i) as i8);
             let mut matched = Simd::<i8, LANE_SIZE>::from(matched);
-            for _scan_idx in 0..self.n_chars {                
+            for _scan_idx in 0..self.n_chars {
                 unsafe {
                     // SAFETY: designed that way in `try_from` method, which is the only way to construct this struct
                     hint::assert_unchecked(test_idx < MAX_LANES);
                 }
                 let char_idx = self.char_positions[test_idx];
-                
-                // it would be nice to use .unwrap_or(&alt), but the compiler isn't able to optimise that because zero might
-                // be a legitimate value in query which shouldn't be replaced (and it relies on being able to do that).
-                // We do opt to treat zero as special, basically we assume no actual query String contains a zero byte.
+
+                // Padding beyond a key's length uses `char_idx - length` (wrapped into u8) rather than 0, exactly like
+                // `try_from` does when building `indexes`, above. We must resolve "is this position past the end of the
+                // query" the same way: via `query.len()`, not by treating a particular byte value as special. Using
+                // `.get(char_idx)` for the bounds check (rather than comparing the fetched byte against a sentinel) keeps
+                // a real NUL byte in the query distinct from "query ran out of bytes here", so binary keys containing
+                // interior NULs still resolve correctly.
                 let alt = char_idx.wrapping_sub(query.len()) as u8;
-                let query_c = *query.get(char_idx).unwrap_or(&0);
-                let query_c = if query_c == 0 { alt } else { query_c };
+                let query_c = query.get(char_idx).copied().unwrap_or(alt);
 
                 let index = self.indexes[test_idx];
                 matched &= index.simd_eq(Simd::<u8, LANE_SIZE>::splat(query_c)).to_int();
@@ -185,17 +195,17 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
             let matched = LANE_SIZE - matched.reduce_max() as usize; // amazingly, using reduce_max(), having started with [16, 15, ..., 1, 0] is faster than using a mask and .first_set()
             matched_idx += if matched < self.n_valid[test_idx -1] && matched != 0 { matched as usize + lane_idx * LANE_SIZE } else { 0 };
         }
-        
+
         unsafe {
             // SAFETY:  see the line above with self.n_valid, and the comment above that line
             hint::assert_unchecked(matched_idx < self.key_vals.len());
         }
 
         let found = &self.key_vals[matched_idx];
-        return if found.0.as_bytes() == query { Some(&found.1) } else { None };
+        return if found.0.as_ref() == query { Some(&found.1) } else { None };
     }
 
-    pub fn iter(&self) -> impl Iterator<Item=&(String, T)> {
+    pub fn iter(&self) -> impl Iterator<Item=&(K, T)> {
         self.key_vals.iter()
     }
 
@@ -203,6 +213,3 @@ where LaneCount<LANE_SIZE>: SupportedLaneCount
         self.key_vals.len()
     }
 }
-
-
-    
\ No newline at end of file
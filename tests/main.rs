@@ -13,16 +13,33 @@ fn test_simple_example(){
         ("now4".into(), DummyVal(1004))
     ];
 
-    let m = SimdPerfectScanMap::<DummyVal, 16, 16>::try_from(kvs); 
+    let m = SimdPerfectScanMap::<String, DummyVal, 16, 16>::try_from(kvs);
     assert!(m.is_ok());
     let m = m.unwrap();
     assert_eq!(m.len(), 4);
 
-    assert_eq!(m.get(&"key1".into()), Some(&DummyVal(1001)));
-    assert!(m.get(&"key1 continued".into()).is_none());
-    assert_eq!(m.get(&"key1longer".into()), Some(&(DummyVal(1002))));
-    assert!(m.get(&"kon1".into()).is_none());
-    assert_eq!(m.get(&"now4".into()),  Some(&DummyVal(1004)));
+    // no allocation needed to query: &str, &[u8] and &String all work directly
+    assert_eq!(m.get("key1"), Some(&DummyVal(1001)));
+    assert!(m.get("key1 continued").is_none());
+    assert_eq!(m.get(&"key1longer".to_string()), Some(&(DummyVal(1002))));
+    assert!(m.get("kon1".as_bytes()).is_none());
+    assert_eq!(m.get("now4"),  Some(&DummyVal(1004)));
+}
+
+
+#[test]
+fn test_binary_keys_with_interior_nul(){
+    let kvs: Vec<(Vec<u8>, DummyVal)> = vec![
+        (b"ab\0cd".to_vec(), DummyVal(2001)),
+        (b"ab\0ce".to_vec(), DummyVal(2002)),
+        (b"abXcd".to_vec(), DummyVal(2003)),
+    ];
+
+    let m = SimdPerfectScanMap::<Vec<u8>, DummyVal, 8, 16>::try_from(kvs).unwrap();
+    assert_eq!(m.get(b"ab\0cd".as_slice()), Some(&DummyVal(2001)));
+    assert_eq!(m.get(b"ab\0ce".as_slice()), Some(&DummyVal(2002)));
+    assert_eq!(m.get(b"abXcd".as_slice()), Some(&DummyVal(2003)));
+    assert!(m.get(b"ab\0cX".as_slice()).is_none());
 }
 
 
@@ -36,12 +53,12 @@ fn test_invalid_example(){
     ];
 
     // you need 3 tests to distinguish between these 4 keys, but we only allow 2 below, which will be a failure
-    let m = SimdPerfectScanMap::<DummyVal, 2, 16>::try_from(kvs); 
+    let m = SimdPerfectScanMap::<String, DummyVal, 2, 16>::try_from(kvs);
     assert!(m.is_err());
     let (err_msg, kvs) = m.unwrap_err(); // note how we regain ownership of kvs within the error payload
-    assert_eq!(err_msg, "Unable to 'solve' with a sufficiently small number of scans"); 
+    assert_eq!(err_msg, "Unable to 'solve' with a sufficiently small number of scans");
 
     // with 3 it's ok
-    let m = SimdPerfectScanMap::<DummyVal, 3, 16>::try_from(kvs); 
+    let m = SimdPerfectScanMap::<String, DummyVal, 3, 16>::try_from(kvs);
     assert!(m.is_ok());
 }
\ No newline at end of file